@@ -102,12 +102,17 @@
 //! references. But if keeping references is very important, then this is your solution.
 
 use std::cell::{Cell, UnsafeCell};
+use std::collections::TryReserveError;
 use std::fmt::{self, Debug};
-use std::iter::FromIterator;
-use std::ops::Index;
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::{Index, IndexMut};
+
+pub mod sync;
+
+pub use crate::sync::SyncAppendList;
 
 // Must be a power of 2
-const FIRST_CHUNK_SIZE: usize = 16;
+pub(crate) const FIRST_CHUNK_SIZE: usize = 16;
 
 /// A list that can be appended to while elements are borrowed
 ///
@@ -153,11 +158,15 @@ const FIRST_CHUNK_SIZE: usize = 16;
 pub struct AppendList<T> {
     chunks: UnsafeCell<Vec<Vec<T>>>,
     len: Cell<usize>,
+    // Size of chunk 0. Always a power of 2. `new()` uses `FIRST_CHUNK_SIZE`;
+    // `with_capacity` rounds its argument up to a power of 2 instead, so a
+    // list built to a known size starts as one contiguous chunk.
+    base: usize,
 }
 
 impl<T> AppendList<T> {
     /// Wrapper to get the list of chunks immutably
-    fn chunks(&self) -> &[Vec<T>] {
+    fn chunk_table(&self) -> &[Vec<T>] {
         unsafe { &*self.chunks.get() }
     }
 
@@ -167,28 +176,40 @@ impl<T> AppendList<T> {
     fn check_invariants(&self) {
         #[cfg(test)]
         {
-            if self.len.get() > 0 {
-                // Correct number of chunks
-                assert_eq!(index_chunk(self.len.get() - 1), self.chunks().len() - 1);
-
-                // Every chunk holds enough items
-                for chunk_id in 0..self.chunks().len() {
-                    assert!(chunk_size(chunk_id) <= self.chunks()[chunk_id].capacity());
-                }
+            let len = self.len.get();
+            let chunks = self.chunk_table();
+
+            // The chunk holding the last real element, if there is one. There
+            // may be further chunks after it: `with_capacity`/`reserve`
+            // preallocate trailing chunks ahead of where `push` has reached.
+            let current_chunk = if len > 0 {
+                Some(index_chunk(self.base, len - 1))
+            } else {
+                None
+            };
 
-                // Intermediate chunks are full
-                for chunk_id in 0..self.chunks().len() - 1 {
-                    assert_eq!(chunk_size(chunk_id), self.chunks()[chunk_id].len());
-                }
+            if let Some(current_chunk) = current_chunk {
+                assert!(chunks.len() > current_chunk);
+            }
 
-                // Last chunk is correct length
-                assert_eq!(
-                    self.chunks().last().unwrap().len(),
-                    self.len.get() - chunk_start(self.chunks().len() - 1)
-                );
-            } else {
-                // No chunks
-                assert_eq!(0, self.chunks().len());
+            // Every chunk, including preallocated ones, holds enough capacity
+            for (chunk_id, chunk) in chunks.iter().enumerate() {
+                assert!(chunk_size(self.base, chunk_id) <= chunk.capacity());
+
+                let expected_chunk_len = match current_chunk {
+                    // Chunks before the current one are full
+                    Some(current_chunk) if chunk_id < current_chunk => {
+                        chunk_size(self.base, chunk_id)
+                    }
+                    // The current chunk holds whatever's left over
+                    Some(current_chunk) if chunk_id == current_chunk => {
+                        len - chunk_start(self.base, chunk_id)
+                    }
+                    // Chunks after the current one are preallocated, but empty
+                    _ => 0,
+                };
+
+                assert_eq!(expected_chunk_len, chunk.len());
             }
         }
     }
@@ -198,13 +219,89 @@ impl<T> AppendList<T> {
         Self {
             chunks: UnsafeCell::new(Vec::new()),
             len: Cell::new(0),
+            base: FIRST_CHUNK_SIZE,
         }
     }
 
-    /// Append an item to the end
+    /// Create a new `AppendList`, preallocated to hold at least `n` elements
+    ///
+    /// Unlike [`new`](AppendList::new), which always starts with a
+    /// `FIRST_CHUNK_SIZE`-element first chunk and doubles from there, this
+    /// sizes the *first* chunk itself to `n` (rounded up to a power of 2), so
+    /// a list built to a known size lives in one contiguous allocation
+    /// instead of crossing the usual 16, 32, 64, ... chunk boundaries on the
+    /// way there.
+    pub fn with_capacity(n: usize) -> Self {
+        let base = if n == 0 { FIRST_CHUNK_SIZE } else { n.next_power_of_two() };
+
+        let list = Self {
+            chunks: UnsafeCell::new(Vec::new()),
+            len: Cell::new(0),
+            base,
+        };
+
+        list.reserve(n);
+
+        list
+    }
+
+    /// Preallocate chunks so the list can hold `additional` more elements
+    /// without allocating
     ///
-    /// Note that this does not require `mut`.
-    pub fn push(&self, item: T) {
+    /// This eagerly allocates every chunk up to and including the one that
+    /// would hold element `len() + additional - 1`, at its full size, without
+    /// changing `len()`. Because later elements are always pushed into
+    /// already-allocated chunks, this removes the repeated small allocations
+    /// (16, then 32, then 64, ...) that building up a large list with plain
+    /// `push` would otherwise pay for.
+    pub fn reserve(&self, additional: usize) {
+        self.check_invariants();
+
+        let target_len = self.len.get() + additional;
+
+        if target_len == 0 {
+            return;
+        }
+
+        let highest_chunk = index_chunk(self.base, target_len - 1);
+
+        // Unsafe code alert! Preallocating a chunk doesn't touch `len`, so it
+        // can't invalidate any existing reference; see `push` for the
+        // invariants this upholds.
+        let mut_chunks = unsafe { &mut *self.chunks.get() };
+
+        while mut_chunks.len() <= highest_chunk {
+            let chunk_id = mut_chunks.len();
+
+            mut_chunks.push(Vec::with_capacity(chunk_size(self.base, chunk_id)));
+        }
+
+        self.check_invariants();
+    }
+
+    /// Append an item to the end, and get back a reference to it
+    ///
+    /// Note that this does not require `mut`. Because chunks are never
+    /// reallocated, the returned reference stays valid for as long as `&self`
+    /// does, even across further calls to `push`, so this replaces the
+    /// `list.push(x); let r = &list[list.len() - 1];` dance with one call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if allocating a new chunk fails. Use [`try_push`](AppendList::try_push)
+    /// to handle that case instead of aborting.
+    pub fn push(&self, item: T) -> &T {
+        self.try_push(item)
+            .unwrap_or_else(|e| panic!("AppendList::push failed to allocate a new chunk: {}", e))
+    }
+
+    /// Append an item to the end, without aborting if allocation fails
+    ///
+    /// This is identical to [`push`](AppendList::push), except that if a new
+    /// chunk needs to be allocated and the allocator reports failure, this
+    /// returns `Err` instead of aborting the process. This mirrors
+    /// `Vec::try_reserve`.
+    pub fn try_push(&self, item: T) -> Result<&T, TryReserveError> {
         self.check_invariants();
 
         // Unsafe code alert!
@@ -213,22 +310,25 @@ impl<T> AppendList<T> {
         // - Only the last chunk may be modified
         // - A chunk cannot ever be reallocated
         // - len must reflect the length
+        // - len/the chunk table are only updated once the insertion can no
+        //   longer fail
         //
         // Invariants are checked in the check_invariants method
         let mut_chunks = unsafe { &mut *self.chunks.get() };
 
         let new_index = self.len.get();
-        let chunk_id = index_chunk(new_index);
+        let chunk_id = index_chunk(self.base, new_index);
 
-        if chunk_id < mut_chunks.len() {
-            // We should always be inserting into the last chunk
-            debug_assert_eq!(chunk_id, mut_chunks.len() - 1);
+        let item_ptr: *const T = if chunk_id < mut_chunks.len() {
+            // This is usually the last chunk, but `with_capacity`/`reserve`
+            // may have preallocated empty chunks after it too.
 
             // Insert into the appropriate chunk
             let chunk = &mut mut_chunks[chunk_id];
 
             // The chunk must not be reallocated! Save the pre-insertion capacity
-            // so we can check it later (debug builds only)
+            // so we can check it later (debug builds only). It already has
+            // capacity for a full chunk, so this insertion can't fail.
             #[cfg(test)]
             let prev_capacity = chunk.capacity();
 
@@ -238,25 +338,37 @@ impl<T> AppendList<T> {
             // Check that the capacity didn't change (debug builds only)
             #[cfg(test)]
             assert_eq!(prev_capacity, chunk.capacity());
+
+            chunk.last().unwrap()
         } else {
             // Need to allocate a new chunk
 
             // New chunk should be the immediate next chunk
             debug_assert_eq!(chunk_id, mut_chunks.len());
 
-            // New chunk must be big enough
-            let mut new_chunk = Vec::with_capacity(chunk_size(chunk_id));
-            debug_assert!(new_chunk.capacity() >= chunk_size(chunk_id));
+            // New chunk must be big enough. Try the allocation fallibly so a
+            // caller can recover instead of aborting on OOM.
+            let mut new_chunk = Vec::new();
+            new_chunk.try_reserve_exact(chunk_size(self.base, chunk_id))?;
+            debug_assert!(new_chunk.capacity() >= chunk_size(self.base, chunk_id));
 
             new_chunk.push(item);
 
+            let item_ptr = new_chunk.last().unwrap() as *const T;
+
             mut_chunks.push(new_chunk);
-        }
+
+            item_ptr
+        };
 
         // Increment the length
-        self.len.set(self.len.get() + 1);
+        self.len.set(new_index + 1);
 
         self.check_invariants();
+
+        // Safety: `item_ptr` points into a chunk that lives behind `self` and
+        // is never reallocated or moved, so it's valid for as long as `&self` is.
+        Ok(unsafe { &*item_ptr })
     }
 
     /// Get the length of the list
@@ -266,6 +378,11 @@ impl<T> AppendList<T> {
         self.len.get()
     }
 
+    /// Returns `true` if the list has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Get an item from the list, if it is in bounds
     ///
     /// Returns `None` if the `index` is out-of-bounds. Note that you can also
@@ -277,45 +394,195 @@ impl<T> AppendList<T> {
             return None;
         }
 
-        let chunk_id = index_chunk(index);
-        let chunk_start = chunk_start(chunk_id);
+        let chunk_id = index_chunk(self.base, index);
+        let chunk_start = chunk_start(self.base, chunk_id);
 
-        return Some(&self.chunks()[chunk_id][index - chunk_start]);
+        Some(&self.chunk_table()[chunk_id][index - chunk_start])
     }
 
     /// Get an iterator over the list
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + FusedIterator {
         self.check_invariants();
 
         AppendListIter {
-            list: &self,
+            list: self,
             index: 0,
+            back: 0,
         }
     }
+
+    /// Get a resumable iterator that starts at `start` and never skips items
+    ///
+    /// Unlike [`iter`](AppendList::iter), this iterator can be resumed after
+    /// it runs out of items: if it reaches the current end of the list, it
+    /// parks there and returns `None` without advancing, and will pick up
+    /// exactly where it left off once more items are pushed. This makes it
+    /// suitable for streaming consumption of a log that's still being
+    /// appended to from elsewhere.
+    ///
+    /// ```
+    /// use appendlist::AppendList;
+    ///
+    /// let list = AppendList::new();
+    /// let mut tail = list.iter_from(0);
+    ///
+    /// list.push(1);
+    /// list.push(2);
+    ///
+    /// assert_eq!(tail.next(), Some(&1));
+    /// assert_eq!(tail.next(), Some(&2));
+    /// assert_eq!(tail.next(), None);
+    ///
+    /// list.push(3);
+    ///
+    /// assert_eq!(tail.next(), Some(&3));
+    /// ```
+    pub fn iter_from(&self, start: usize) -> TailIter<'_, T> {
+        self.check_invariants();
+
+        TailIter {
+            list: self,
+            index: start,
+        }
+    }
+
+    /// Get a bounded iterator over `range` that polls for not-yet-pushed items
+    ///
+    /// Like [`iter_from`](AppendList::iter_from), this returns `None` without
+    /// advancing while `range.start` is out of bounds, so a consumer can poll
+    /// it for a slice that hasn't been pushed yet. Unlike `iter_from`, it
+    /// stops for good once it reaches `range.end`, regardless of how long the
+    /// list grows.
+    pub fn range(&self, range: std::ops::Range<usize>) -> RangeIter<'_, T> {
+        self.check_invariants();
+
+        RangeIter {
+            list: self,
+            index: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Get a mutable reference to an item in the list, if it is in bounds
+    ///
+    /// Returns `None` if the `index` is out-of-bounds. Note that you can also
+    /// index with `[]`, which will panic on out-of-bounds.
+    ///
+    /// This takes `&mut self` rather than `&self`, so, unlike [`get`](AppendList::get),
+    /// it doesn't need any unsafe code: `&mut self` statically guarantees there
+    /// are no other references into the list, mutable or not.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.check_invariants();
+
+        if index >= self.len() {
+            return None;
+        }
+
+        let chunk_id = index_chunk(self.base, index);
+        let chunk_start = chunk_start(self.base, chunk_id);
+
+        let chunks = self.chunks.get_mut();
+
+        Some(&mut chunks[chunk_id][index - chunk_start])
+    }
+
+    /// Get a mutable iterator over the list
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.check_invariants();
+
+        self.chunks.get_mut().iter_mut().flatten()
+    }
+
+    /// Get the list's elements as a sequence of contiguous slices, in order
+    ///
+    /// Indexing element-by-element through [`get`](AppendList::get) recomputes
+    /// which chunk holds the index on every call, which is wasteful for a bulk
+    /// scan. This exposes the underlying chunks directly (the last one sliced
+    /// down to its populated length) so a caller can feed whole `&[T]` runs
+    /// into something like `extend_from_slice` or a SIMD loop, and only pay
+    /// the chunk lookup once per chunk instead of once per element.
+    pub fn chunks(&self) -> impl Iterator<Item = &[T]> {
+        self.check_invariants();
+
+        let len = self.len();
+        let base = self.base;
+        let chunk_count = self.chunk_table().len();
+
+        (0..chunk_count).map(move |chunk_id| {
+            // Look each chunk up afresh instead of borrowing the chunk table for
+            // the iterator's whole life: `push` and `reserve` may reallocate it
+            let chunk = &self.chunk_table()[chunk_id];
+            let populated = len.saturating_sub(chunk_start(base, chunk_id)).min(chunk.len());
+            &chunk[..populated]
+        })
+    }
+
+    /// Get the chunk-aligned slice containing `index`, and `index`'s offset within it
+    ///
+    /// Returns `None` if `index` is out of bounds. This is the slice-oriented
+    /// counterpart to [`get`](AppendList::get): instead of a single element,
+    /// it hands back the whole populated chunk the element lives in, plus
+    /// where in that slice the element is, so a caller can keep iterating
+    /// within the chunk without repeating the `index_chunk`/`chunk_start` math.
+    pub fn chunk_at(&self, index: usize) -> Option<(&[T], usize)> {
+        self.check_invariants();
+
+        if index >= self.len() {
+            return None;
+        }
+
+        let chunk_id = index_chunk(self.base, index);
+        let chunk_start = chunk_start(self.base, chunk_id);
+        let offset = index - chunk_start;
+
+        let chunk = &self.chunk_table()[chunk_id];
+        let populated = (self.len() - chunk_start).min(chunk.len());
+
+        Some((&chunk[..populated], offset))
+    }
+
+    /// Consume the list and consolidate its chunks into one contiguous `Vec`
+    ///
+    /// This pays the chunked, reference-stable cost of `AppendList` during a
+    /// build phase, then hands back a plain `Vec` for a subsequent read-heavy
+    /// phase, trading away reference stability for the better cache behavior
+    /// of one contiguous allocation near chunk boundaries.
+    pub fn into_vec(self) -> Vec<T> {
+        let mut out = Vec::with_capacity(self.len.get());
+
+        for chunk in self.chunks.into_inner() {
+            out.extend(chunk);
+        }
+
+        out
+    }
 }
 
-const fn chunk_size(chunk_id: usize) -> usize {
-    // First chunk is FIRST_CHUNK_SIZE, subsequent chunks double each time
-    FIRST_CHUNK_SIZE << chunk_id
+// `base` is the size of chunk 0; it must be a power of 2. `AppendList` passes
+// its own `base` (`FIRST_CHUNK_SIZE` unless built by `with_capacity`);
+// `SyncAppendList` always passes `FIRST_CHUNK_SIZE`.
+pub(crate) const fn chunk_size(base: usize, chunk_id: usize) -> usize {
+    // First chunk is `base`, subsequent chunks double each time
+    base << chunk_id
 }
 
-const fn chunk_start(chunk_id: usize) -> usize {
+pub(crate) const fn chunk_start(base: usize, chunk_id: usize) -> usize {
     // This looks like magic, but I promise it works
     // Essentially, each chunk is the size of the sum of all chunks before
     // it. Except that the first chunk is different: it "should" be preceded
     // by a whole list of chunks that sum to its size, but it's not. Therefore,
     // there's a "missing" set of chunks the size of the first chunk, so
     // later chunks need to be updated.
-    chunk_size(chunk_id) - FIRST_CHUNK_SIZE
+    chunk_size(base, chunk_id) - base
 }
 
-const fn index_chunk(index: usize) -> usize {
+pub(crate) const fn index_chunk(base: usize, index: usize) -> usize {
     // This *is* magic
-    floor_log2(index + FIRST_CHUNK_SIZE) - floor_log2(FIRST_CHUNK_SIZE)
+    floor_log2(index + base) - floor_log2(base)
 }
 
 #[inline]
-const fn floor_log2(x: usize) -> usize {
+pub(crate) const fn floor_log2(x: usize) -> usize {
     const BITS_PER_BYTE: usize = 8;
 
     BITS_PER_BYTE * std::mem::size_of::<usize>() - (x.leading_zeros() as usize) - 1
@@ -336,33 +603,133 @@ impl<T> Index<usize> for AppendList<T> {
     }
 }
 
+impl<T> IndexMut<usize> for AppendList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index)
+            .expect("AppendList indexed beyond its length")
+    }
+}
+
 impl<T> FromIterator<T> for AppendList<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let list = Self::new();
+        let mut list = Self::new();
 
-        for item in iter {
-            list.push(item);
-        }
+        list.extend(iter);
 
         list
     }
 }
 
+impl<T> Extend<T> for AppendList<T> {
+    /// Extend the list with the contents of an iterator
+    ///
+    /// Unlike repeatedly calling [`push`](AppendList::push), this fills each
+    /// chunk directly in a tight loop instead of recomputing which chunk to
+    /// insert into for every element, and only allocates a new chunk once the
+    /// current one is completely full.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.check_invariants();
+
+        let mut iter = iter.into_iter().peekable();
+
+        // If the iterator has a known lower bound, preallocate however many
+        // correctly-sized chunks are needed to hold it, rather than letting
+        // the loop below grow one default-sized chunk at a time.
+        let (lower_bound, _) = iter.size_hint();
+        if lower_bound > 0 {
+            self.reserve(lower_bound);
+        }
+
+        // Bail out before allocating a chunk if there's nothing to put in
+        // it: an empty iterator must be a true no-op.
+        if iter.peek().is_none() {
+            return;
+        }
+
+        loop {
+            let len = self.len.get();
+            let chunk_id = index_chunk(self.base, len);
+
+            // Unsafe code alert! `&mut self` guarantees no other references
+            // into the list exist, so this doesn't need the care `push` does.
+            let chunks = self.chunks.get_mut();
+
+            if chunk_id == chunks.len() {
+                // The size hint didn't cover this chunk (or there was none):
+                // allocate it at its default size.
+                chunks.push(Vec::with_capacity(chunk_size(self.base, chunk_id)));
+            }
+
+            let chunk = &mut chunks[chunk_id];
+            let remaining_capacity =
+                chunk_size(self.base, chunk_id) - (len - chunk_start(self.base, chunk_id));
+
+            let mut filled = 0;
+            while filled < remaining_capacity {
+                match iter.next() {
+                    Some(item) => {
+                        chunk.push(item);
+                        filled += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            self.len.set(len + filled);
+
+            let chunk_is_full = filled == remaining_capacity;
+
+            self.check_invariants();
+
+            if !chunk_is_full {
+                // The iterator ran out before filling this chunk
+                break;
+            }
+        }
+    }
+}
+
 impl<T: Debug> Debug for AppendList<T> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_list().entries(self.iter()).finish()
     }
 }
 
+impl<T> IntoIterator for AppendList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consume the list, yielding owned elements in order
+    ///
+    /// This goes through [`into_vec`](AppendList::into_vec), so it's a plain
+    /// `Vec` iterator under the hood rather than the per-element `get` lookup
+    /// the borrowing [`iter`](AppendList::iter) uses.
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
 struct AppendListIter<'l, T> {
     list: &'l AppendList<T>,
     index: usize,
+    // How many items have been taken off the back by `next_back`. Kept as a
+    // count, rather than an absolute index, so that it stays meaningful if
+    // the list grows while the iterator is alive.
+    back: usize,
 }
 
 impl<'l, T> Iterator for AppendListIter<'l, T> {
     type Item = &'l T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.index + self.back >= self.list.len() {
+            // Park past any possible length so that items pushed later can't
+            // revive a finished iterator (it is a `FusedIterator`)
+            self.index = usize::MAX;
+            self.back = 0;
+            return None;
+        }
+
         let item = self.list.get(self.index);
 
         self.index += 1;
@@ -371,12 +738,88 @@ impl<'l, T> Iterator for AppendListIter<'l, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.list.len() - self.index;
+        let remaining = self
+            .list
+            .len()
+            .saturating_sub(self.index)
+            .saturating_sub(self.back);
 
         (remaining, Some(remaining))
     }
 }
 
+impl<'l, T> DoubleEndedIterator for AppendListIter<'l, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.list.len();
+
+        if self.index + self.back >= len {
+            return None;
+        }
+
+        self.back += 1;
+
+        self.list.get(len - self.back)
+    }
+}
+
+impl<'l, T> ExactSizeIterator for AppendListIter<'l, T> {}
+
+impl<'l, T> FusedIterator for AppendListIter<'l, T> {}
+
+/// A resumable iterator, returned by [`AppendList::iter_from`]
+///
+/// Unlike the iterator returned by [`AppendList::iter`], this parks at the
+/// current end of the list instead of permanently skipping past it, so
+/// calling `next` again after the list grows resumes exactly where it left
+/// off.
+pub struct TailIter<'l, T> {
+    list: &'l AppendList<T>,
+    index: usize,
+}
+
+impl<'l, T> Iterator for TailIter<'l, T> {
+    type Item = &'l T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.list.get(self.index);
+
+        if item.is_some() {
+            self.index += 1;
+        }
+
+        item
+    }
+}
+
+/// A bounded, pollable iterator over a range, returned by [`AppendList::range`]
+///
+/// Like [`TailIter`], this parks without advancing while its next item
+/// hasn't been pushed yet. Unlike `TailIter`, it stops for good once it
+/// reaches the end of its range.
+pub struct RangeIter<'l, T> {
+    list: &'l AppendList<T>,
+    index: usize,
+    end: usize,
+}
+
+impl<'l, T> Iterator for RangeIter<'l, T> {
+    type Item = &'l T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+
+        let item = self.list.get(self.index);
+
+        if item.is_some() {
+            self.index += 1;
+        }
+
+        item
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -406,6 +849,48 @@ mod test {
         assert_eq!(i.next(), None);
     }
 
+    #[test]
+    fn iterator_rev() {
+        let l: AppendList<i32> = (0..100).collect();
+
+        let collected: Vec<&i32> = l.iter().rev().collect();
+
+        for item in 0..100 {
+            assert_eq!(*collected[99 - item as usize], item);
+        }
+    }
+
+    #[test]
+    fn iterator_meet_in_the_middle() {
+        let l: AppendList<i32> = (0..10).collect();
+        let mut i = l.iter();
+
+        assert_eq!(i.next(), Some(&0));
+        assert_eq!(i.next_back(), Some(&9));
+        assert_eq!(i.next(), Some(&1));
+        assert_eq!(i.next_back(), Some(&8));
+
+        assert_eq!(i.len(), 6);
+
+        let middle: Vec<&i32> = i.collect();
+        assert_eq!(middle, vec![&2, &3, &4, &5, &6, &7]);
+    }
+
+    #[test]
+    fn iterator_exact_size_and_fused() {
+        let l: AppendList<i32> = (0..5).collect();
+        let mut i = l.iter();
+
+        assert_eq!(i.len(), 5);
+
+        for _ in 0..5 {
+            assert!(i.next().is_some());
+        }
+
+        assert_eq!(i.next(), None);
+        assert_eq!(i.next(), None);
+    }
+
     #[test]
     fn iterator_size_hint() {
         let l: AppendList<i32> = AppendList::new();
@@ -431,6 +916,64 @@ mod test {
         assert_eq!(i.size_hint(), (0, Some(0)));
     }
 
+    #[test]
+    fn tail_iter_resumes_after_exhaustion() {
+        let l = AppendList::new();
+        let mut tail = l.iter_from(0);
+
+        assert_eq!(tail.next(), None);
+
+        l.push(1);
+        l.push(2);
+
+        assert_eq!(tail.next(), Some(&1));
+        assert_eq!(tail.next(), Some(&2));
+        assert_eq!(tail.next(), None);
+        assert_eq!(tail.next(), None);
+
+        l.push(3);
+
+        assert_eq!(tail.next(), Some(&3));
+        assert_eq!(tail.next(), None);
+    }
+
+    #[test]
+    fn tail_iter_from_middle() {
+        let l: AppendList<i32> = (0..10).collect();
+        let mut tail = l.iter_from(5);
+
+        for item in 5..10 {
+            assert_eq!(tail.next(), Some(&item));
+        }
+
+        assert_eq!(tail.next(), None);
+    }
+
+    #[test]
+    fn range_iter_polls_for_unpushed_items() {
+        let l = AppendList::new();
+        let mut r = l.range(1..3);
+
+        assert_eq!(r.next(), None);
+
+        l.push(0);
+
+        assert_eq!(r.next(), None);
+
+        l.push(1);
+        l.push(2);
+        l.push(3);
+
+        assert_eq!(r.next(), Some(&1));
+        assert_eq!(r.next(), Some(&2));
+        assert_eq!(r.next(), None);
+
+        l.push(4);
+
+        // range is bounded, so it stays exhausted even though the list grew
+        assert_eq!(r.next(), None);
+    }
+
     #[test]
     fn first_chunk_size_is_power_of_2() {
         assert_eq!(floor_log2(FIRST_CHUNK_SIZE) as f64, log2(FIRST_CHUNK_SIZE));
@@ -438,28 +981,54 @@ mod test {
 
     #[test]
     fn chunk_sizes_make_sense() {
-        assert_eq!(chunk_size(0), FIRST_CHUNK_SIZE);
+        assert_eq!(chunk_size(FIRST_CHUNK_SIZE, 0), FIRST_CHUNK_SIZE);
 
         let mut index = 0;
 
         for chunk in 0..20 {
             // Each chunk starts just after the previous one ends
-            assert_eq!(chunk_start(chunk), index);
-            index += chunk_size(chunk);
+            assert_eq!(chunk_start(FIRST_CHUNK_SIZE, chunk), index);
+            index += chunk_size(FIRST_CHUNK_SIZE, chunk);
         }
     }
 
     #[test]
     fn index_chunk_matches_up() {
         for index in 0..1_000_000 {
-            let chunk_id = index_chunk(index);
+            let chunk_id = index_chunk(FIRST_CHUNK_SIZE, index);
 
             // Each index happens after its chunk start and before its chunk end
-            assert!(index >= chunk_start(chunk_id));
-            assert!(index < chunk_start(chunk_id) + chunk_size(chunk_id));
+            assert!(index >= chunk_start(FIRST_CHUNK_SIZE, chunk_id));
+            assert!(
+                index < chunk_start(FIRST_CHUNK_SIZE, chunk_id) + chunk_size(FIRST_CHUNK_SIZE, chunk_id)
+            );
         }
     }
 
+    #[test]
+    fn with_capacity_rounds_up_to_a_power_of_2_and_starts_as_one_chunk() {
+        let l: AppendList<i32> = AppendList::with_capacity(100);
+
+        assert_eq!(l.base, 128);
+        assert_eq!(l.chunk_table().len(), 1);
+        assert_eq!(l.chunk_table()[0].capacity(), 128);
+
+        for i in 0..100 {
+            l.push(i);
+        }
+
+        // Still one chunk after filling it up to the requested capacity
+        assert_eq!(l.chunk_table().len(), 1);
+    }
+
+    #[test]
+    fn with_capacity_zero_behaves_like_new() {
+        let l: AppendList<i32> = AppendList::with_capacity(0);
+
+        assert_eq!(l.base, FIRST_CHUNK_SIZE);
+        assert_eq!(l.len(), 0);
+    }
+
     #[test]
     fn empty_list() {
         let n: AppendList<usize> = AppendList::new();
@@ -483,6 +1052,232 @@ mod test {
         test_big_list(1_000_000);
     }
 
+    #[test]
+    fn with_capacity_preallocates_without_changing_len() {
+        let l: AppendList<i32> = AppendList::with_capacity(1_000);
+
+        assert_eq!(l.len(), 0);
+        assert_eq!(l.get(0), None);
+
+        for i in 0..1_000 {
+            l.push(i);
+        }
+
+        for i in 0..1_000 {
+            assert_eq!(l[i as usize], i);
+        }
+    }
+
+    #[test]
+    fn reserve_on_nonempty_list() {
+        let l: AppendList<i32> = (0..10).collect();
+
+        l.reserve(1_000);
+
+        assert_eq!(l.len(), 10);
+
+        for i in 10..1_010 {
+            l.push(i);
+        }
+
+        for i in 0..1_010 {
+            assert_eq!(l[i as usize], i);
+        }
+    }
+
+    #[test]
+    fn from_iterator_reserves_from_size_hint() {
+        let l: AppendList<i32> = (0..1_000).collect();
+
+        assert_eq!(l.len(), 1_000);
+
+        for i in 0..1_000 {
+            assert_eq!(l[i as usize], i);
+        }
+    }
+
+    #[test]
+    fn extend_empty_iterator_is_a_noop() {
+        let mut l: AppendList<i32> = AppendList::new();
+
+        l.extend(std::iter::empty());
+
+        assert_eq!(l.len(), 0);
+        assert_eq!(l.get(0), None);
+        // A true no-op: no chunk should have been allocated up front.
+        assert_eq!(l.chunks().count(), 0);
+    }
+
+    #[test]
+    fn extend_fills_across_chunk_boundaries() {
+        let mut l: AppendList<i32> = (0..10).collect();
+
+        // Crosses several chunk boundaries: the first chunk holds 16 elements,
+        // the second 32, and each later one twice as many as the one before.
+        l.extend(10..500);
+
+        assert_eq!(l.len(), 500);
+
+        for i in 0..500 {
+            assert_eq!(l[i as usize], i);
+        }
+    }
+
+    #[test]
+    fn extend_on_already_full_chunk_allocates_the_next_one() {
+        let mut l: AppendList<i32> = (0..16).collect();
+
+        assert_eq!(l.len(), 16);
+
+        l.extend(16..20);
+
+        assert_eq!(l.len(), 20);
+
+        for i in 0..20 {
+            assert_eq!(l[i as usize], i);
+        }
+    }
+
+    #[test]
+    fn chunks_cover_every_element_in_order() {
+        let l: AppendList<i32> = (0..1_000).collect();
+
+        let flattened: Vec<i32> = l.chunks().flatten().copied().collect();
+        let expected: Vec<i32> = (0..1_000).collect();
+
+        assert_eq!(flattened, expected);
+    }
+
+    #[test]
+    fn chunks_on_partially_filled_last_chunk() {
+        let l: AppendList<i32> = (0..20).collect();
+
+        let chunks: Vec<&[i32]> = l.chunks().collect();
+
+        // FIRST_CHUNK_SIZE is 16, so this is a full first chunk and a
+        // partially-filled second chunk of 4 elements
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 16);
+        assert_eq!(chunks[1].len(), 4);
+    }
+
+    #[test]
+    fn chunks_on_reserved_list_with_trailing_empty_chunks() {
+        let l: AppendList<i32> = AppendList::new();
+        l.reserve(100);
+
+        for i in 0..10 {
+            l.push(i);
+        }
+
+        // `reserve` preallocated chunks past the one `push` has reached;
+        // those trailing chunks are empty, not just partially filled, and
+        // `chunks()` must not underflow computing their populated length.
+        let chunks: Vec<&[i32]> = l.chunks().collect();
+
+        let flattened: Vec<i32> = chunks.into_iter().flatten().copied().collect();
+        assert_eq!(flattened, (0..10).collect::<Vec<i32>>());
+
+        for i in 0..10 {
+            let (chunk, offset) = l.chunk_at(i).unwrap();
+            assert_eq!(chunk[offset], l[i]);
+        }
+    }
+
+    #[test]
+    fn chunk_at_matches_get() {
+        let l: AppendList<i32> = (0..1_000).collect();
+
+        assert_eq!(l.chunk_at(1_000), None);
+
+        for i in 0..1_000 {
+            let (chunk, offset) = l.chunk_at(i).unwrap();
+            assert_eq!(chunk[offset], l[i]);
+        }
+    }
+
+    #[test]
+    fn get_mut_and_index_mut() {
+        let mut l: AppendList<i32> = (0..100).collect();
+
+        assert_eq!(l.get_mut(1_000), None);
+
+        *l.get_mut(0).unwrap() += 1;
+        l[1] *= 10;
+
+        assert_eq!(l[0], 1);
+        assert_eq!(l[1], 10);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut l: AppendList<i32> = (0..100).collect();
+
+        for item in l.iter_mut() {
+            *item *= 2;
+        }
+
+        for i in 0..100 {
+            assert_eq!(l[i], (i * 2) as i32);
+        }
+    }
+
+    #[test]
+    fn push_returns_a_reference_to_the_new_item() {
+        let l = AppendList::new();
+
+        let foo = l.push("foo");
+        let bar = l.push("bar");
+
+        assert_eq!(*foo, "foo");
+        assert_eq!(*bar, "bar");
+        assert_eq!(l[0], "foo");
+        assert_eq!(l[1], "bar");
+    }
+
+    #[test]
+    fn try_push_succeeds() {
+        let l = AppendList::new();
+
+        for i in 0..1_000 {
+            let r = l.try_push(i).unwrap();
+            assert_eq!(*r, i);
+        }
+
+        for i in 0..1_000 {
+            assert_eq!(l.get(i), Some(&i));
+        }
+    }
+
+    #[test]
+    fn into_vec_consolidates_chunks() {
+        let l = AppendList::new();
+
+        for i in 0..1_000 {
+            l.push(i);
+        }
+
+        let v = l.into_vec();
+
+        assert_eq!(v.len(), 1_000);
+
+        for (i, item) in v.into_iter().enumerate() {
+            assert_eq!(item, i);
+        }
+    }
+
+    #[test]
+    fn into_iter_yields_owned_items() {
+        let l: AppendList<String> = AppendList::new();
+
+        l.push("foo".to_string());
+        l.push("bar".to_string());
+
+        let items: Vec<String> = l.into_iter().collect();
+
+        assert_eq!(items, vec!["foo".to_string(), "bar".to_string()]);
+    }
+
     fn test_big_list(size: usize) {
         let l = AppendList::new();
         let mut refs = Vec::new();
@@ -496,8 +1291,8 @@ mod test {
             assert_eq!(l.len(), i + 1);
         }
 
-        for i in 0..size {
-            assert_eq!(Some(&refs[i]), l.get(i));
+        for (i, r) in refs.iter().enumerate() {
+            assert_eq!(Some(r), l.get(i));
         }
     }
 }
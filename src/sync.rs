@@ -0,0 +1,371 @@
+//! A thread-safe append list.
+//!
+//! [`SyncAppendList`] uses more unsafe code than the single-threaded
+//! [`AppendList`](crate::AppendList), in exchange for `Send + Sync`: many
+//! threads can hold references into the list and read through them while a single
+//! thread pushes. Reading through a reference already handed out takes no lock;
+//! each `get` takes a shared read lock on the chunk table just for the lookup.
+
+use std::mem::MaybeUninit;
+use std::ops::Index;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::{chunk_size, chunk_start, index_chunk, FIRST_CHUNK_SIZE};
+
+/// A thread-safe list that can be appended to while elements are borrowed
+///
+/// This is the multi-threaded sibling of [`AppendList`](crate::AppendList). It
+/// preserves the same guarantee -- you can hold a reference into the list and
+/// push a new value on without invalidating it -- but does so across threads:
+/// any number of readers may call [`get`](SyncAppendList::get) concurrently
+/// with a writer calling [`push`](SyncAppendList::push).
+///
+/// Cloning a `SyncAppendList` is cheap and gives you a new handle onto the
+/// same underlying storage (it is backed by an `Arc`), which is the usual way
+/// to share one across threads.
+///
+/// ```
+/// use appendlist::sync::SyncAppendList;
+/// use std::thread;
+///
+/// let list = SyncAppendList::new();
+/// list.push(1);
+///
+/// let first_item = &list[0];
+///
+/// let writer = list.clone();
+/// let handle = thread::spawn(move || {
+///     writer.push(2);
+/// });
+/// handle.join().unwrap();
+///
+/// assert_eq!(*first_item, list[0]);
+/// ```
+///
+/// `SyncAppendList<T>` is only `Send`/`Sync` when `T` is: [`get`](SyncAppendList::get)
+/// hands out `&T` that other threads can hold and dereference concurrently, so a
+/// `!Sync` element type like `Cell<i32>` would let two threads race on the same
+/// cell. This fails to compile:
+///
+/// ```compile_fail
+/// use appendlist::sync::SyncAppendList;
+/// use std::cell::Cell;
+///
+/// fn assert_sync<T: Sync>() {}
+///
+/// assert_sync::<SyncAppendList<Cell<i32>>>();
+/// ```
+///
+/// # Implementation details
+///
+/// Like `AppendList`, this is backed by a `Vec` of chunks, sized and indexed
+/// with the same `chunk_size`/`chunk_start`/`index_chunk` math. The chunk
+/// table itself is a `Vec` of raw pointers to heap-allocated `[MaybeUninit<T>]`
+/// slices, guarded by an `RwLock` so that growing the table (which only
+/// happens when a chunk fills up) excludes readers just long enough to push
+/// the new pointer. Individual chunks are never reallocated or moved, so once
+/// a reader has looked up a chunk's pointer it can keep dereferencing it
+/// without holding the lock.
+///
+/// A `push` takes a `Mutex` for its whole duration, so that only one thread
+/// is ever writing at a time; it writes the new element into its
+/// preallocated slot and only then "publishes" it by bumping an `AtomicUsize`
+/// length with `Release` ordering. A reader loads that length with `Acquire`
+/// ordering and only ever dereferences slots below it, which is why readers
+/// never take the writers' `Mutex` and hold the chunk table's read lock only
+/// while looking up a chunk pointer.
+pub struct SyncAppendList<T> {
+    inner: Arc<Inner<T>>,
+}
+
+struct Inner<T> {
+    // Guards growth of the chunk table. Taken for reading by every `get`,
+    // and for writing only when `push` needs to allocate a new chunk.
+    chunks: RwLock<Vec<*mut [MaybeUninit<T>]>>,
+    // Published length. Readers must load this with `Acquire` before
+    // trusting that index `i < len` is initialized.
+    len: AtomicUsize,
+    // Serializes pushes so that only one thread is ever allocating a chunk
+    // or claiming a slot at a time.
+    write_lock: Mutex<()>,
+}
+
+// Safety: `Inner` only ever exposes `&T`/`T` through synchronized paths (the
+// `RwLock` for the chunk table, the `AtomicUsize` for length, the `Mutex` for
+// writers), so it can be moved to another thread whenever `T` can.
+unsafe impl<T: Send> Send for Inner<T> {}
+// Safety: `get` hands out `&T` that multiple threads can hold and dereference
+// concurrently, so sharing `Inner` across threads is only sound when `T` is
+// itself `Sync` (e.g. `&Cell<i32>` read from two threads at once would be a
+// data race otherwise).
+unsafe impl<T: Send + Sync> Sync for Inner<T> {}
+
+impl<T> SyncAppendList<T> {
+    /// Create a new `SyncAppendList`
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                chunks: RwLock::new(Vec::new()),
+                len: AtomicUsize::new(0),
+                write_lock: Mutex::new(()),
+            }),
+        }
+    }
+
+    /// Append an item to the end
+    ///
+    /// Note that this does not require `mut`. It does take an internal lock
+    /// for its duration, so concurrent `push` calls from other threads will
+    /// block until this one finishes. Concurrent `get` calls only wait while it
+    /// adds a new chunk to the chunk table.
+    pub fn push(&self, item: T) {
+        let _writer = self.inner.write_lock.lock().unwrap();
+
+        let new_index = self.inner.len.load(Ordering::Relaxed);
+        let chunk_id = index_chunk(FIRST_CHUNK_SIZE, new_index);
+
+        let slot = {
+            let chunks = self.inner.chunks.read().unwrap();
+
+            if chunk_id < chunks.len() {
+                debug_assert_eq!(chunk_id, chunks.len() - 1);
+
+                let chunk_start = chunk_start(FIRST_CHUNK_SIZE, chunk_id);
+                let chunk = chunks[chunk_id];
+
+                // Safety: the chunk pointer was allocated with `chunk_size(chunk_id)`
+                // elements and is never reallocated or freed while `self.inner` is alive.
+                unsafe { (*chunk).as_mut_ptr().add(new_index - chunk_start) }
+            } else {
+                debug_assert_eq!(chunk_id, chunks.len());
+
+                drop(chunks);
+
+                let mut new_chunk = Vec::with_capacity(chunk_size(FIRST_CHUNK_SIZE, chunk_id));
+                new_chunk.extend(
+                    (0..chunk_size(FIRST_CHUNK_SIZE, chunk_id)).map(|_| MaybeUninit::uninit()),
+                );
+
+                let chunk_ptr: *mut [MaybeUninit<T>] = Box::into_raw(new_chunk.into_boxed_slice());
+
+                self.inner.chunks.write().unwrap().push(chunk_ptr);
+
+                // Safety: freshly allocated, so offset 0 is always in bounds.
+                unsafe { (*chunk_ptr).as_mut_ptr() }
+            }
+        };
+
+        // Safety: `slot` points at an uninitialized, exclusively-owned element
+        // slot: no other thread can reach it until `len` is bumped below.
+        unsafe { (*slot).write(item) };
+
+        // Release: publishes both the element write above and (if we took the
+        // allocation branch) the new chunk pointer to any thread that
+        // subsequently loads `len` with `Acquire`.
+        self.inner.len.store(new_index + 1, Ordering::Release);
+    }
+
+    /// Get the length of the list
+    pub fn len(&self) -> usize {
+        self.inner.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the list has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get an item from the list, if it is in bounds
+    ///
+    /// Returns `None` if the `index` is out-of-bounds. Note that you can also
+    /// index with `[]`, which will panic on out-of-bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let chunk_id = index_chunk(FIRST_CHUNK_SIZE, index);
+        let chunk_start = chunk_start(FIRST_CHUNK_SIZE, chunk_id);
+
+        let chunks = self.inner.chunks.read().unwrap();
+        let chunk = chunks[chunk_id];
+
+        // Safety: `index < self.len()` was just checked against a length
+        // loaded with `Acquire`, which pairs with the `Release` store in
+        // `push` that happens only after the slot is written. The chunk
+        // pointer is never reallocated or freed while `self.inner` is alive.
+        let slot = unsafe { &*(*chunk).as_ptr().add(index - chunk_start) };
+
+        Some(unsafe { slot.assume_init_ref() })
+    }
+}
+
+impl<T> Default for SyncAppendList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for SyncAppendList<T> {
+    /// Get a new handle to the same underlying list
+    ///
+    /// This is how a `SyncAppendList` is usually shared across threads: clone
+    /// it and move the clone into the other thread.
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Index<usize> for SyncAppendList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index)
+            .expect("SyncAppendList indexed beyond its length")
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        let mut remaining = *self.len.get_mut();
+
+        for (chunk_id, &chunk) in self.chunks.get_mut().unwrap().iter().enumerate() {
+            let populated = remaining.min(chunk_size(FIRST_CHUNK_SIZE, chunk_id));
+
+            // Safety: `chunk` was allocated by `push` with exactly
+            // `chunk_size(chunk_id)` elements, and the first `populated` of
+            // them were initialized before `len` was published past them.
+            unsafe {
+                let slice = &mut *chunk;
+
+                for slot in &mut slice[..populated] {
+                    ptr::drop_in_place(slot.as_mut_ptr());
+                }
+
+                drop(Box::from_raw(chunk));
+            }
+
+            remaining -= populated;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::thread;
+
+    // Compile-time assertion: `SyncAppendList<T>` is `Send + Sync` whenever
+    // `T` is. Generic functions only type-check when their bounds are met,
+    // so this is checked at `cargo build`/`cargo test` time, not at runtime.
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn send_and_sync_for_send_sync_element_type() {
+        assert_send_sync::<SyncAppendList<i32>>();
+    }
+
+    #[test]
+    fn empty_list() {
+        let l: SyncAppendList<usize> = SyncAppendList::new();
+
+        assert_eq!(l.len(), 0);
+        assert!(l.is_empty());
+        assert_eq!(l.get(0), None);
+
+        let d: SyncAppendList<usize> = SyncAppendList::default();
+        assert_eq!(d.len(), 0);
+    }
+
+    #[test]
+    fn push_and_get() {
+        let l = SyncAppendList::new();
+
+        for i in 0..1_000 {
+            assert_eq!(l.len(), i);
+            l.push(i);
+            assert_eq!(l[i], i);
+        }
+
+        for i in 0..1_000 {
+            assert_eq!(l.get(i), Some(&i));
+        }
+
+        assert_eq!(l.get(1_000), None);
+    }
+
+    #[test]
+    fn push_while_borrowed() {
+        let list = SyncAppendList::new();
+
+        list.push(1);
+        let first_item = &list[0];
+        list.push(2);
+        let second_item = &list[1];
+
+        assert_eq!(*first_item, list[0]);
+        assert_eq!(*second_item, list[1]);
+    }
+
+    #[test]
+    fn clone_shares_storage() {
+        let a = SyncAppendList::new();
+        a.push("hello");
+
+        let b = a.clone();
+        b.push("world");
+
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[0], "hello");
+        assert_eq!(a[1], "world");
+    }
+
+    #[test]
+    fn concurrent_readers_and_writer() {
+        let list = SyncAppendList::new();
+
+        for i in 0..100 {
+            list.push(i);
+        }
+
+        let writer = list.clone();
+        let writer_handle = thread::spawn(move || {
+            for i in 100..1_000 {
+                writer.push(i);
+            }
+        });
+
+        let mut reader_handles = Vec::new();
+
+        for _ in 0..4 {
+            let reader = list.clone();
+            reader_handles.push(thread::spawn(move || {
+                let mut last_len = 0;
+
+                while last_len < 1_000 {
+                    let len = reader.len();
+                    assert!(len >= last_len);
+
+                    for i in 0..len {
+                        assert_eq!(*reader.get(i).unwrap(), i);
+                    }
+
+                    last_len = len;
+                    thread::yield_now();
+                }
+            }));
+        }
+
+        writer_handle.join().unwrap();
+
+        for handle in reader_handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(list.len(), 1_000);
+    }
+}